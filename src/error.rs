@@ -0,0 +1,70 @@
+//! Positioned diagnostics: rendering `[line N]` errors with the offending
+//! source line quoted underneath and a caret underline over the span.
+
+/// A half-open range of character offsets into the source text.
+pub type Span = (usize, usize);
+
+/// Render `message` as a located diagnostic against `source`: the
+/// `[line N]` prefix, the source line containing `span`, and a caret
+/// underline beneath the exact columns `span` covers.
+pub fn render(source: &[char], line: usize, span: Span, message: &str) -> String {
+    let (start, end) = span;
+
+    let line_start = source[..start]
+        .iter()
+        .rposition(|&c| c == '\n')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let line_end = source[start..]
+        .iter()
+        .position(|&c| c == '\n')
+        .map(|i| start + i)
+        .unwrap_or(source.len());
+
+    let line_text: String = source[line_start..line_end].iter().collect();
+    let col = start - line_start;
+    let width = end.min(line_end).saturating_sub(start).max(1);
+
+    format!(
+        "[line {line}] Error: {message}\n{line_text}\n{}{}",
+        " ".repeat(col),
+        "^".repeat(width)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_points_at_span() {
+        let source: Vec<char> = "1 + @".chars().collect();
+        let rendered = render(&source, 1, (4, 5), "Unexpected character @.");
+        assert_eq!(
+            rendered,
+            "[line 1] Error: Unexpected character @.\n1 + @\n    ^".to_string()
+        );
+    }
+
+    #[test]
+    fn test_render_clamps_caret_width_to_line() {
+        // An unterminated string's span runs past the newline it contains;
+        // the caret underline must stop at the end of the printed line.
+        let source: Vec<char> = "\"abc\ndef".chars().collect();
+        let rendered = render(&source, 1, (0, 8), "Unterminated string.");
+        assert_eq!(
+            rendered,
+            "[line 1] Error: Unterminated string.\n\"abc\n^^^^".to_string()
+        );
+    }
+
+    #[test]
+    fn test_render_on_second_line() {
+        let source: Vec<char> = "var x = 1\n\"unterminated".chars().collect();
+        let rendered = render(&source, 2, (10, 23), "Unterminated string.");
+        assert_eq!(
+            rendered,
+            "[line 2] Error: Unterminated string.\n\"unterminated\n^^^^^^^^^^^^^".to_string()
+        );
+    }
+}
@@ -10,7 +10,7 @@ pub trait Visitor<R> {
     fn parenthesize(&mut self, name: &str, exprs: Vec<&Expr>) -> R;
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum Expr {
     Binary(Box<Expr>, Token, Box<Expr>),
     Grouping(Box<Expr>),
@@ -35,7 +35,7 @@ impl Expr {
     }
 }
 
-struct AstPrinter;
+pub struct AstPrinter;
 impl Visitor<String> for AstPrinter {
     fn visit_binary_expr(&mut self, expr: &Expr) -> String {
         if let Expr::Binary(left, operator, right) = expr {
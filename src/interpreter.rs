@@ -0,0 +1,253 @@
+use core::fmt;
+
+use crate::expr::{Expr, Visitor};
+use crate::lexer::{Token, TokenType};
+
+/// A runtime value produced by evaluating an `Expr`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Str(String),
+    Bool(bool),
+    Nil,
+}
+
+impl Value {
+    /// Lox truthiness: everything is truthy except `nil` and `false`.
+    fn is_truthy(&self) -> bool {
+        !matches!(self, Value::Nil | Value::Bool(false))
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{n}"),
+            Value::Str(s) => write!(f, "{s}"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Nil => write!(f, "nil"),
+        }
+    }
+}
+
+/// An error raised while evaluating an `Expr`, carrying the token whose
+/// operation failed so the caller can render a located diagnostic.
+#[derive(Debug, Clone)]
+pub struct RuntimeError {
+    pub token: Token,
+    pub message: String,
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[line {}] Error: {}", self.token.line, self.message)
+    }
+}
+
+impl RuntimeError {
+    pub fn render(&self, source: &[char]) -> String {
+        crate::error::render(source, self.token.line, self.token.span, &self.message)
+    }
+}
+
+/// A tree-walking interpreter: a `Visitor` that evaluates an `Expr` down to
+/// a single `Value` instead of printing it.
+#[derive(Default)]
+pub struct Interpreter;
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn evaluate(&mut self, expr: &Expr) -> Result<Value, RuntimeError> {
+        self.print(expr)
+    }
+
+    fn as_number(operator: &Token, value: &Value) -> Result<f64, RuntimeError> {
+        match value {
+            Value::Number(n) => Ok(*n),
+            _ => Err(RuntimeError {
+                token: operator.clone(),
+                message: "Operand must be a number.".to_string(),
+            }),
+        }
+    }
+
+    fn numeric_op(
+        operator: &Token,
+        left: &Value,
+        right: &Value,
+        op: impl Fn(f64, f64) -> f64,
+    ) -> Result<Value, RuntimeError> {
+        let a = Self::as_number(operator, left)?;
+        let b = Self::as_number(operator, right)?;
+        Ok(Value::Number(op(a, b)))
+    }
+
+    fn comparison(
+        operator: &Token,
+        left: &Value,
+        right: &Value,
+        op: impl Fn(f64, f64) -> bool,
+    ) -> Result<Value, RuntimeError> {
+        let a = Self::as_number(operator, left)?;
+        let b = Self::as_number(operator, right)?;
+        Ok(Value::Bool(op(a, b)))
+    }
+}
+
+impl Visitor<Result<Value, RuntimeError>> for Interpreter {
+    fn visit_binary_expr(&mut self, expr: &Expr) -> Result<Value, RuntimeError> {
+        if let Expr::Binary(left, operator, right) = expr {
+            let left = self.evaluate(left)?;
+            let right = self.evaluate(right)?;
+            match &operator.token_type {
+                TokenType::Plus => match (&left, &right) {
+                    (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a + b)),
+                    (Value::Str(a), Value::Str(b)) => Ok(Value::Str(format!("{a}{b}"))),
+                    _ => Err(RuntimeError {
+                        token: operator.clone(),
+                        message: "Operands must be two numbers or two strings.".to_string(),
+                    }),
+                },
+                TokenType::Minus => Self::numeric_op(operator, &left, &right, |a, b| a - b),
+                TokenType::Star => Self::numeric_op(operator, &left, &right, |a, b| a * b),
+                TokenType::Slash => {
+                    let a = Self::as_number(operator, &left)?;
+                    let b = Self::as_number(operator, &right)?;
+                    if b == 0.0 {
+                        return Err(RuntimeError {
+                            token: operator.clone(),
+                            message: "Division by zero.".to_string(),
+                        });
+                    }
+                    Ok(Value::Number(a / b))
+                }
+                TokenType::Greater => Self::comparison(operator, &left, &right, |a, b| a > b),
+                TokenType::GreaterEqual => {
+                    Self::comparison(operator, &left, &right, |a, b| a >= b)
+                }
+                TokenType::Less => Self::comparison(operator, &left, &right, |a, b| a < b),
+                TokenType::LessEqual => Self::comparison(operator, &left, &right, |a, b| a <= b),
+                TokenType::EqualEqual => Ok(Value::Bool(left == right)),
+                TokenType::BangEqual => Ok(Value::Bool(left != right)),
+                _ => unreachable!("the parser only ever emits valid binary operators"),
+            }
+        } else {
+            panic!("Expected Binary expression");
+        }
+    }
+
+    fn visit_grouping_expr(&mut self, expr: &Expr) -> Result<Value, RuntimeError> {
+        if let Expr::Grouping(expression) = expr {
+            self.evaluate(expression)
+        } else {
+            panic!("Expected Grouping expression");
+        }
+    }
+
+    fn visit_literal_expr(&mut self, expr: &Expr) -> Result<Value, RuntimeError> {
+        if let Expr::Literal(value) = expr {
+            match value {
+                Some(token) => match &token.token_type {
+                    TokenType::Number(n) => Ok(Value::Number(*n)),
+                    TokenType::Str(s) => Ok(Value::Str(s.clone())),
+                    TokenType::True => Ok(Value::Bool(true)),
+                    TokenType::False => Ok(Value::Bool(false)),
+                    TokenType::Nil => Ok(Value::Nil),
+                    _ => unreachable!("the parser only ever wraps literal tokens"),
+                },
+                None => Ok(Value::Nil),
+            }
+        } else {
+            panic!("Expected Literal expression");
+        }
+    }
+
+    fn visit_unary_expr(&mut self, expr: &Expr) -> Result<Value, RuntimeError> {
+        if let Expr::Unary(operator, right) = expr {
+            let right = self.evaluate(right)?;
+            match &operator.token_type {
+                TokenType::Minus => {
+                    let n = Self::as_number(operator, &right)?;
+                    Ok(Value::Number(-n))
+                }
+                TokenType::Bang => Ok(Value::Bool(!right.is_truthy())),
+                _ => unreachable!("the parser only ever emits valid unary operators"),
+            }
+        } else {
+            panic!("Expected Unary expression");
+        }
+    }
+
+    fn print(&mut self, expr: &Expr) -> Result<Value, RuntimeError> {
+        expr.accept(self as &mut dyn Visitor<Result<Value, RuntimeError>>)
+    }
+
+    fn parenthesize(&mut self, _name: &str, _exprs: Vec<&Expr>) -> Result<Value, RuntimeError> {
+        panic!("Interpreter evaluates expressions directly and never calls parenthesize");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Scanner;
+    use crate::parser::Parser;
+
+    fn eval(source: &str) -> Result<Value, RuntimeError> {
+        let mut scanner = Scanner::new(source.to_string());
+        let tokens = scanner.scan_tokens();
+        let expr = Parser::new(tokens).parse().expect("valid expression");
+        Interpreter::new().evaluate(&expr)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(eval("1 + 2 * 3").unwrap(), Value::Number(7.0));
+    }
+
+    #[test]
+    fn test_string_concatenation() {
+        assert_eq!(
+            eval("\"foo\" + \"bar\"").unwrap(),
+            Value::Str("foobar".to_string())
+        );
+    }
+
+    #[test]
+    fn test_comparison_and_equality() {
+        assert_eq!(eval("1 < 2 == (2 > 1)").unwrap(), Value::Bool(true));
+    }
+
+    // The scanner has no identifier/keyword support yet, so `nil`/`false`
+    // literals are built by hand rather than scanned from source text.
+    #[test]
+    fn test_truthiness() {
+        let negate = |right: Expr| {
+            Expr::Unary(Token::new(TokenType::Bang, "!".to_string(), 1), Box::new(right))
+        };
+        let nil = Expr::Literal(Some(Token::new(TokenType::Nil, "nil".to_string(), 1)));
+        let is_false = Expr::Literal(Some(Token::new(TokenType::False, "false".to_string(), 1)));
+        let zero = Expr::Literal(Some(Token::new(TokenType::Number(0.0), "0".to_string(), 1)));
+
+        let mut interpreter = Interpreter::new();
+        assert_eq!(interpreter.evaluate(&negate(nil)).unwrap(), Value::Bool(true));
+        assert_eq!(
+            interpreter.evaluate(&negate(is_false)).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(interpreter.evaluate(&negate(zero)).unwrap(), Value::Bool(false));
+    }
+
+    #[test]
+    fn test_type_mismatch_is_runtime_error() {
+        assert!(eval("1 + \"a\"").is_err());
+    }
+
+    #[test]
+    fn test_division_by_zero_is_runtime_error() {
+        assert!(eval("1 / 0").is_err());
+    }
+}
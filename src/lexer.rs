@@ -16,6 +16,9 @@ pub enum TokenType {
     Semicolon,
     Slash,
     Star,
+    Amper,
+    Pipe,
+    Caret,
 
     // One or two character tokens.
     Bang,
@@ -68,6 +71,9 @@ impl fmt::Display for TokenType {
             Semicolon => write!(f, ";"),
             Slash => write!(f, "/"),
             Star => write!(f, "*"),
+            Amper => write!(f, "&"),
+            Pipe => write!(f, "|"),
+            Caret => write!(f, "^"),
 
             // One or two character tokens.
             Bang => write!(f, "!"),
@@ -107,21 +113,38 @@ impl fmt::Display for TokenType {
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct Token {
-    token_type: TokenType,
-    lexeme: String,
-    line: usize,
+    pub(crate) token_type: TokenType,
+    pub(crate) lexeme: String,
+    pub(crate) line: usize,
+    /// Start/end character offsets of this token's lexeme in the source.
+    /// Carried purely for diagnostics, so it's excluded from `PartialEq`.
+    pub(crate) span: crate::error::Span,
+}
+
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        self.token_type == other.token_type
+            && self.lexeme == other.lexeme
+            && self.line == other.line
+    }
 }
 
 impl Token {
-    fn new(token_type: TokenType, lexeme: String, line: usize) -> Self {
+    pub(crate) fn new(token_type: TokenType, lexeme: String, line: usize) -> Self {
         Self {
             token_type,
             lexeme,
             line,
+            span: (0, 0),
         }
     }
+
+    pub(crate) fn with_span(mut self, span: crate::error::Span) -> Self {
+        self.span = span;
+        self
+    }
 }
 
 impl fmt::Display for Token {
@@ -139,7 +162,7 @@ impl fmt::Display for Token {
 
 #[derive(Debug, Default)]
 pub struct Scanner {
-    source: String,
+    chars: Vec<char>,
     tokens: Vec<Token>,
     start: usize,
     current: usize,
@@ -151,11 +174,16 @@ pub struct Scanner {
 impl Scanner {
     pub fn new(source: String) -> Self {
         let mut s = Self::default();
-        s.source = source;
+        s.chars = source.chars().collect();
         s.line = 1;
         s
     }
 
+    /// Whether scanning hit a lexical error (e.g. an unterminated string).
+    pub fn had_error(&self) -> bool {
+        self.lox.had_error
+    }
+
     pub fn scan_tokens(&mut self) -> Vec<Token> {
         while !self.is_at_end() {
             // We are at the beginning of the next lexeme.
@@ -163,8 +191,10 @@ impl Scanner {
             self.scan_token();
         }
 
-        self.tokens
-            .push(Token::new(TokenType::Eof, "".to_string(), self.line));
+        self.tokens.push(
+            Token::new(TokenType::Eof, "".to_string(), self.line)
+                .with_span((self.current, self.current)),
+        );
         self.tokens.clone()
     }
 
@@ -181,6 +211,9 @@ impl Scanner {
             '+' => self.add_token(TokenType::Plus),
             ';' => self.add_token(TokenType::Semicolon),
             '*' => self.add_token(TokenType::Star),
+            '&' => self.add_token(TokenType::Amper),
+            '|' => self.add_token(TokenType::Pipe),
+            '^' => self.add_token(TokenType::Caret),
             '!' => {
                 if self.match_next('=') {
                     self.add_token(TokenType::BangEqual);
@@ -226,14 +259,24 @@ impl Scanner {
                 if self.is_digit(c) {
                     self.number();
                 } else {
-                    self.lox
-                        .error(self.line, format!("Unexpected character {c}."));
+                    self.lox.error_at(
+                        &self.chars,
+                        self.line,
+                        (self.start, self.current),
+                        &format!("Unexpected character {c}."),
+                    );
                 }
             }
         }
     }
 
     fn number(&mut self) {
+        if self.chars[self.start] == '0' && matches!(self.peek(), 'x' | 'X' | 'b' | 'B' | 'o' | 'O')
+        {
+            self.radix_number();
+            return;
+        }
+
         while self.is_digit(self.peek()) {
             self.advance();
         }
@@ -247,40 +290,172 @@ impl Scanner {
             }
         }
 
-        let num_str = &self.source[self.start..self.current];
+        let num_str: String = self.chars[self.start..self.current].iter().collect();
         if let Ok(n) = num_str.parse::<f64>() {
             self.add_token(TokenType::Number(n));
         } else {
-            self.lox.error(self.line, "Expected a decimal.".to_string());
+            self.lox.error_at(
+                &self.chars,
+                self.line,
+                (self.start, self.current),
+                "Expected a decimal.",
+            );
+        }
+    }
+
+    /// Parses a `0x`/`0X` (hex), `0b`/`0B` (binary), or `0o`/`0O` (octal)
+    /// integer literal, storing the result as a `Number(f64)` so the rest
+    /// of the pipeline is unchanged.
+    fn radix_number(&mut self) {
+        let radix = match self.advance() {
+            'x' | 'X' => 16,
+            'b' | 'B' => 2,
+            'o' | 'O' => 8,
+            _ => unreachable!("caller already checked the prefix"),
+        };
+
+        let digits_start = self.current;
+        while self.peek().is_digit(radix) {
+            self.advance();
+        }
+        let digits: String = self.chars[digits_start..self.current].iter().collect();
+
+        match u64::from_str_radix(&digits, radix) {
+            Ok(n) => self.add_token(TokenType::Number(n as f64)),
+            Err(_) => self.lox.error_at(
+                &self.chars,
+                self.line,
+                (self.start, self.current),
+                "Malformed integer literal.",
+            ),
         }
     }
 
     fn string(&mut self) {
+        let mut value = String::new();
+
         while self.peek() != '"' && !self.is_at_end() {
-            if self.peek() == '\n' {
+            let escape_start = self.current;
+            let c = self.advance();
+            if c == '\n' {
                 self.line += 1;
+                value.push(c);
+            } else if c == '\\' {
+                if let Some(decoded) = self.decode_escape(escape_start) {
+                    value.push(decoded);
+                }
+            } else {
+                value.push(c);
             }
-            self.advance();
         }
 
         if self.is_at_end() {
-            self.lox
-                .error(self.line, "Unterminated string.".to_string());
+            self.lox.error_at(
+                &self.chars,
+                self.line,
+                (self.start, self.current),
+                "Unterminated string.",
+            );
+            return;
         }
 
         // The closing ".
         self.advance();
 
-        // Trim the surrounding quotes.
-        let value = &self.source[self.start + 1..self.current - 1];
-        self.add_token(TokenType::Str(value.to_string()))
+        // `lexeme` (set by `add_token` from `self.start`/`self.current`)
+        // keeps the original quoted text; `value` is the decoded string.
+        self.add_token(TokenType::Str(value))
+    }
+
+    /// Decodes the escape sequence starting right after the backslash at
+    /// `escape_start`. Returns `None` (after reporting a diagnostic) for an
+    /// unknown escape or a malformed/out-of-range `\u{...}`.
+    fn decode_escape(&mut self, escape_start: usize) -> Option<char> {
+        if self.is_at_end() {
+            self.lox.error_at(
+                &self.chars,
+                self.line,
+                (escape_start, self.current),
+                "Malformed escape sequence.",
+            );
+            return None;
+        }
+
+        let c = self.advance();
+        if c == '\n' {
+            self.line += 1;
+        }
+        match c {
+            'n' => Some('\n'),
+            't' => Some('\t'),
+            'r' => Some('\r'),
+            '\\' => Some('\\'),
+            '"' => Some('"'),
+            '0' => Some('\0'),
+            'u' => self.decode_unicode_escape(escape_start),
+            other => {
+                self.lox.error_at(
+                    &self.chars,
+                    self.line,
+                    (escape_start, self.current),
+                    &format!("Malformed escape sequence '\\{}'.", other.escape_default()),
+                );
+                None
+            }
+        }
+    }
+
+    /// Decodes a `\u{...}` Unicode code point escape.
+    fn decode_unicode_escape(&mut self, escape_start: usize) -> Option<char> {
+        if self.peek() != '{' {
+            self.lox.error_at(
+                &self.chars,
+                self.line,
+                (escape_start, self.current),
+                "Malformed escape sequence '\\u'; expected '{'.",
+            );
+            return None;
+        }
+        self.advance();
+
+        let digits_start = self.current;
+        while self.peek() != '}' && !self.is_at_end() {
+            if self.advance() == '\n' {
+                self.line += 1;
+            }
+        }
+        let digits: String = self.chars[digits_start..self.current].iter().collect();
+
+        if self.is_at_end() {
+            self.lox.error_at(
+                &self.chars,
+                self.line,
+                (escape_start, self.current),
+                "Malformed escape sequence: unterminated '\\u{...}'.",
+            );
+            return None;
+        }
+        self.advance(); // consume '}'
+
+        match u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32) {
+            Some(c) => Some(c),
+            None => {
+                self.lox.error_at(
+                    &self.chars,
+                    self.line,
+                    (escape_start, self.current),
+                    &format!("Invalid unicode escape '\\u{{{digits}}}'."),
+                );
+                None
+            }
+        }
     }
 
     fn match_next(&mut self, expected: char) -> bool {
         if self.is_at_end() {
             return false;
         }
-        if self.source.chars().nth(self.current).unwrap() != expected {
+        if self.chars[self.current] != expected {
             return false;
         }
         self.current += 1;
@@ -288,23 +463,11 @@ impl Scanner {
     }
 
     fn peek(&self) -> char {
-        if self.is_at_end() {
-            return '\0';
-        }
-        if let Some(c) = self.source.chars().nth(self.current) {
-            return c;
-        }
-        '\0'
+        self.chars.get(self.current).copied().unwrap_or('\0')
     }
 
     fn peek_next(&self) -> char {
-        if self.current + 1 >= self.source.len() {
-            return '\0';
-        }
-        if let Some(c) = self.source.chars().nth(self.current + 1) {
-            return c;
-        }
-        '\0'
+        self.chars.get(self.current + 1).copied().unwrap_or('\0')
     }
 
     fn is_digit(&self, c: char) -> bool {
@@ -312,24 +475,20 @@ impl Scanner {
     }
 
     fn is_at_end(&self) -> bool {
-        self.current >= self.source.len()
+        self.current >= self.chars.len()
     }
 
     fn advance(&mut self) -> char {
-        let ch: char;
-        if let Some(c) = self.source.chars().nth(self.current) {
-            ch = c;
-        } else {
-            ch = '\0';
-        }
+        let ch = self.chars[self.current];
         self.current += 1;
         ch
     }
 
     fn add_token(&mut self, token_type: TokenType) {
-        let text = &self.source[self.start..self.current];
-        self.tokens
-            .push(Token::new(token_type, text.to_string(), self.line));
+        let text: String = self.chars[self.start..self.current].iter().collect();
+        self.tokens.push(
+            Token::new(token_type, text, self.line).with_span((self.start, self.current)),
+        );
     }
 }
 
@@ -380,7 +539,119 @@ mod tests {
         );
 
         // The last lexeme seen was a three-character "// ", so the start cursor is 3 behind current.
-        assert_eq!(scanner.start, source.len() - 3);
-        assert_eq!(scanner.current, source.len());
+        let char_count = source.chars().count();
+        assert_eq!(scanner.start, char_count - 3);
+        assert_eq!(scanner.current, char_count);
+    }
+
+    #[test]
+    fn test_scanner_multibyte_utf8() {
+        // "héllo" has a 2-byte 'é', so byte offsets and char offsets diverge.
+        let source = "\"héllo\" + 1".to_string();
+
+        let mut scanner = Scanner::new(source);
+        let tokens = scanner.scan_tokens();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::new(Str("héllo".to_string()), "\"héllo\"".to_string(), 1),
+                Token::new(Plus, "+".to_string(), 1),
+                Token::new(Number(1.0), "1".to_string(), 1),
+                Token::new(Eof, "".to_string(), 1),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_token_span_tracks_lexeme_bounds() {
+        let mut scanner = Scanner::new("1 + 22".to_string());
+        let tokens = scanner.scan_tokens();
+        assert_eq!(tokens[0].span, (0, 1)); // "1"
+        assert_eq!(tokens[1].span, (2, 3)); // "+"
+        assert_eq!(tokens[2].span, (4, 6)); // "22"
+    }
+
+    #[test]
+    fn test_radix_number_literals() {
+        let mut scanner = Scanner::new("0xFF 0b101 0o17".to_string());
+        let tokens = scanner.scan_tokens();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::new(Number(255.0), "0xFF".to_string(), 1),
+                Token::new(Number(5.0), "0b101".to_string(), 1),
+                Token::new(Number(15.0), "0o17".to_string(), 1),
+                Token::new(Eof, "".to_string(), 1),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_malformed_radix_number_literal_errors() {
+        let mut scanner = Scanner::new("0x".to_string());
+        scanner.scan_tokens();
+        assert!(scanner.had_error());
+    }
+
+    #[test]
+    fn test_bitwise_operator_tokens() {
+        let mut scanner = Scanner::new("1 & 2 | 3 ^ 4".to_string());
+        let tokens = scanner.scan_tokens();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::new(Number(1.0), "1".to_string(), 1),
+                Token::new(Amper, "&".to_string(), 1),
+                Token::new(Number(2.0), "2".to_string(), 1),
+                Token::new(Pipe, "|".to_string(), 1),
+                Token::new(Number(3.0), "3".to_string(), 1),
+                Token::new(Caret, "^".to_string(), 1),
+                Token::new(Number(4.0), "4".to_string(), 1),
+                Token::new(Eof, "".to_string(), 1),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_string_escape_sequences() {
+        let mut scanner = Scanner::new(r#""a\nb\t\"c\"\\""#.to_string());
+        let tokens = scanner.scan_tokens();
+        assert_eq!(tokens[0].token_type, Str("a\nb\t\"c\"\\".to_string()));
+        assert_eq!(tokens[0].lexeme, r#""a\nb\t\"c\"\\""#.to_string());
+    }
+
+    #[test]
+    fn test_string_unicode_escape() {
+        let mut scanner = Scanner::new(r#""\u{1F600}""#.to_string());
+        let tokens = scanner.scan_tokens();
+        assert_eq!(tokens[0].token_type, Str("\u{1F600}".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_escape_sequence_errors() {
+        let mut scanner = Scanner::new(r#""\q""#.to_string());
+        scanner.scan_tokens();
+        assert!(scanner.had_error());
+    }
+
+    #[test]
+    fn test_invalid_unicode_escape_errors() {
+        let mut scanner = Scanner::new(r#""\u{FFFFFF}""#.to_string());
+        scanner.scan_tokens();
+        assert!(scanner.had_error());
+    }
+
+    #[test]
+    fn test_escaped_literal_newline_advances_line_count() {
+        // "a\" then a real newline then "b" on the next line, then a token
+        // on line 3. The escaped newline must bump `self.line` just like
+        // the main loop in `string()` does, or every later token's line
+        // number comes out off by one.
+        let source = format!("\"a\\{}b\"\n1", '\n');
+        let mut scanner = Scanner::new(source);
+        let tokens = scanner.scan_tokens();
+        assert_eq!(tokens[0].token_type, Str("ab".to_string()));
+        assert_eq!(tokens[1].token_type, Number(1.0));
+        assert_eq!(tokens[1].line, 3);
     }
 }
@@ -1,18 +1,36 @@
-use crate::lexer::{Scanner, Token};
+use crate::error::{self, Span};
+use crate::expr::{AstPrinter, Visitor};
+use crate::interpreter::Interpreter;
+use crate::lexer::Scanner;
+use crate::parser::Parser;
 use std::{fs, io, process};
 
+/// Which compiler stage `Lox::run` should stop at and print, selected by
+/// the `--tokens`/`-t` and `--ast`/`-a` CLI flags.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Mode {
+    #[default]
+    Run,
+    Tokens,
+    Ast,
+}
+
 #[derive(Debug, Default)]
 pub struct Lox {
     pub had_error: bool,
+    pub had_runtime_error: bool,
+    pub mode: Mode,
 }
 
 impl Lox {
-    pub fn run_file(&self, path: String) -> Result<(), io::Error> {
+    pub fn run_file(&mut self, path: String) -> Result<(), io::Error> {
         match fs::read_to_string(path) {
             Ok(content) => {
-                Self::run(content);
+                self.run(content);
                 if self.had_error {
                     process::exit(65);
+                } else if self.had_runtime_error {
+                    process::exit(70);
                 } else {
                     Ok(())
                 }
@@ -25,28 +43,58 @@ impl Lox {
         let mut buffer = String::new();
         loop {
             println!("> ");
+            buffer.clear();
             io::stdin().read_line(&mut buffer)?;
-            Self::run(buffer.clone());
+            self.run(buffer.clone());
             self.had_error = false;
+            self.had_runtime_error = false;
         }
     }
 
-    fn run(source: String) {
+    fn run(&mut self, source: String) {
+        let chars: Vec<char> = source.chars().collect();
+
         let mut scanner = Scanner::new(source);
-        let tokens: Vec<Token> = scanner.scan_tokens();
+        let tokens = scanner.scan_tokens();
+        if scanner.had_error() {
+            self.had_error = true;
+            return;
+        }
 
-        // For now, just print the tokens.
-        for token in tokens {
-            println!("{:?}", token);
+        if self.mode == Mode::Tokens {
+            for token in &tokens {
+                println!("{token}");
+            }
+            return;
         }
-    }
 
-    pub fn error(&mut self, line: usize, message: String) {
-        self.report(line, "".to_string(), message);
+        let expr = match Parser::new(tokens).parse() {
+            Ok(expr) => expr,
+            Err(err) => {
+                println!("{}", err.render(&chars));
+                self.had_error = true;
+                return;
+            }
+        };
+
+        if self.mode == Mode::Ast {
+            println!("{}", AstPrinter.print(&expr));
+            return;
+        }
+
+        match Interpreter::new().evaluate(&expr) {
+            Ok(value) => println!("{value}"),
+            Err(err) => {
+                println!("{}", err.render(&chars));
+                self.had_runtime_error = true;
+            }
+        }
     }
 
-    fn report(&mut self, line: usize, hint: String, message: String) {
-        println!("[line {line}] Error {hint}: {message}");
+    /// Reports an error located at `span` within `source`, quoting the
+    /// offending source line with a caret underline.
+    pub fn error_at(&mut self, source: &[char], line: usize, span: Span, message: &str) {
+        println!("{}", error::render(source, line, span, message));
         self.had_error = true;
     }
 }
@@ -1,21 +1,41 @@
 mod error;
 mod expr;
+mod interpreter;
 mod lexer;
 mod lox;
+mod parser;
 
-use crate::lox::Lox;
+use crate::lox::{Lox, Mode};
 use std::{env, process};
 
 fn main() {
-    let mut lox = Lox::default();
-    let args = env::args();
-    if args.len() == 1 {
-        println!("Usage: jlox [script]");
-        process::exit(64);
-    } else if args.len() > 1 {
-        let argvs: Vec<String> = args.collect();
-        _ = lox.run_file(argvs.get(1).unwrap().to_string());
-    } else {
-        _ = lox.run_prompt();
+    let argvs: Vec<String> = env::args().collect();
+
+    let mut mode = Mode::default();
+    let mut script = None;
+    for arg in &argvs[1..] {
+        match arg.as_str() {
+            "--tokens" | "-t" => mode = Mode::Tokens,
+            "--ast" | "-a" => mode = Mode::Ast,
+            path => script = Some(path.to_string()),
+        }
+    }
+
+    let mut lox = Lox {
+        mode,
+        ..Lox::default()
+    };
+
+    match script {
+        Some(path) => {
+            _ = lox.run_file(path);
+        }
+        None if argvs.len() == 1 => {
+            println!("Usage: jlox [--tokens|-t] [--ast|-a] [script]");
+            process::exit(64);
+        }
+        None => {
+            _ = lox.run_prompt();
+        }
     }
 }
@@ -0,0 +1,363 @@
+use core::fmt;
+
+use crate::expr::Expr;
+use crate::lexer::{Token, TokenType};
+
+/// An error produced while turning a token stream into an `Expr` tree.
+///
+/// Each variant carries the offending token (and its line) so the caller
+/// can render a located diagnostic.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ParseError {
+    UnexpectedToken { token: Token, line: usize, message: String },
+    ExpectedExpression { token: Token, line: usize },
+    MissingRightParen { token: Token, line: usize },
+}
+
+impl ParseError {
+    fn token(&self) -> &Token {
+        match self {
+            ParseError::UnexpectedToken { token, .. }
+            | ParseError::ExpectedExpression { token, .. }
+            | ParseError::MissingRightParen { token, .. } => token,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            ParseError::UnexpectedToken { message, .. } => message.clone(),
+            ParseError::ExpectedExpression { .. } => "Expect expression.".to_string(),
+            ParseError::MissingRightParen { .. } => "Expect ')' after expression.".to_string(),
+        }
+    }
+
+    /// Renders this error as a located diagnostic against `source`, quoting
+    /// the offending source line with a caret underline under the token.
+    pub fn render(&self, source: &[char]) -> String {
+        let token = self.token();
+        crate::error::render(source, token.line, token.span, &self.message())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let token = self.token();
+        write!(
+            f,
+            "[line {}] Error at '{}': {}",
+            token.line,
+            token.lexeme,
+            self.message()
+        )
+    }
+}
+
+/// A recursive-descent parser that turns a flat `Vec<Token>` into an `Expr`
+/// tree, following the standard Lox precedence cascade:
+/// `expression -> equality -> comparison -> term -> factor -> unary -> primary`.
+pub struct Parser {
+    tokens: Vec<Token>,
+    current: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Self { tokens, current: 0 }
+    }
+
+    pub fn parse(&mut self) -> Result<Expr, ParseError> {
+        let expr = self.expression()?;
+        if !self.is_at_end() {
+            let err = ParseError::UnexpectedToken {
+                token: self.peek().clone(),
+                line: self.peek().line,
+                message: "Expect end of expression.".to_string(),
+            };
+            self.synchronize();
+            return Err(err);
+        }
+        Ok(expr)
+    }
+
+    fn expression(&mut self) -> Result<Expr, ParseError> {
+        self.equality()
+    }
+
+    fn equality(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.comparison()?;
+        while self.match_token(&[TokenType::BangEqual, TokenType::EqualEqual]) {
+            let operator = self.previous().clone();
+            let right = self.comparison()?;
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.term()?;
+        while self.match_token(&[
+            TokenType::Greater,
+            TokenType::GreaterEqual,
+            TokenType::Less,
+            TokenType::LessEqual,
+        ]) {
+            let operator = self.previous().clone();
+            let right = self.term()?;
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.factor()?;
+        while self.match_token(&[TokenType::Minus, TokenType::Plus]) {
+            let operator = self.previous().clone();
+            let right = self.factor()?;
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn factor(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.unary()?;
+        while self.match_token(&[TokenType::Slash, TokenType::Star]) {
+            let operator = self.previous().clone();
+            let right = self.unary()?;
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.match_token(&[TokenType::Bang, TokenType::Minus]) {
+            let operator = self.previous().clone();
+            let right = self.unary()?;
+            return Ok(Expr::Unary(operator, Box::new(right)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        match &self.peek().token_type {
+            TokenType::False
+            | TokenType::True
+            | TokenType::Nil
+            | TokenType::Number(_)
+            | TokenType::Str(_) => {
+                let token = self.advance().clone();
+                Ok(Expr::Literal(Some(token)))
+            }
+            TokenType::LeftParen => {
+                self.advance();
+                let expr = self.expression()?;
+                self.consume_right_paren()?;
+                Ok(Expr::Grouping(Box::new(expr)))
+            }
+            _ => Err(ParseError::ExpectedExpression {
+                token: self.peek().clone(),
+                line: self.peek().line,
+            }),
+        }
+    }
+
+    fn consume_right_paren(&mut self) -> Result<(), ParseError> {
+        if self.check(&TokenType::RightParen) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(ParseError::MissingRightParen {
+                token: self.peek().clone(),
+                line: self.peek().line,
+            })
+        }
+    }
+
+    /// Discards tokens until the next statement boundary (a `;` or a
+    /// keyword that starts a new statement). This grammar only ever parses
+    /// one top-level expression per `parse()` call, so today this just
+    /// leaves the cursor at a recoverable point after a trailing-tokens
+    /// error; reporting more than one `ParseError` per run will fall out
+    /// once parsing grows a loop over multiple top-level items.
+    fn synchronize(&mut self) {
+        self.advance();
+        while !self.is_at_end() {
+            if self.previous().token_type == TokenType::Semicolon {
+                return;
+            }
+            if matches!(
+                self.peek().token_type,
+                TokenType::Class
+                    | TokenType::Fun
+                    | TokenType::Var
+                    | TokenType::For
+                    | TokenType::If
+                    | TokenType::While
+                    | TokenType::Print
+                    | TokenType::Return
+            ) {
+                return;
+            }
+            self.advance();
+        }
+    }
+
+    fn match_token(&mut self, types: &[TokenType]) -> bool {
+        for token_type in types {
+            if self.check(token_type) {
+                self.advance();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn check(&self, token_type: &TokenType) -> bool {
+        if self.is_at_end() {
+            return false;
+        }
+        std::mem::discriminant(&self.peek().token_type) == std::mem::discriminant(token_type)
+    }
+
+    fn advance(&mut self) -> &Token {
+        if !self.is_at_end() {
+            self.current += 1;
+        }
+        self.previous()
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.peek().token_type == TokenType::Eof
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.current]
+    }
+
+    fn previous(&self) -> &Token {
+        &self.tokens[self.current - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::Visitor;
+    use crate::lexer::Scanner;
+
+    fn parse(source: &str) -> Result<Expr, ParseError> {
+        let mut scanner = Scanner::new(source.to_string());
+        let tokens = scanner.scan_tokens();
+        Parser::new(tokens).parse()
+    }
+
+    struct Stringifier;
+    impl Visitor<String> for Stringifier {
+        fn visit_binary_expr(&mut self, expr: &Expr) -> String {
+            if let Expr::Binary(left, operator, right) = expr {
+                self.parenthesize(&operator.lexeme, vec![left, right])
+            } else {
+                panic!("Expected Binary expression");
+            }
+        }
+
+        fn visit_grouping_expr(&mut self, expr: &Expr) -> String {
+            if let Expr::Grouping(expression) = expr {
+                self.parenthesize("group", vec![expression])
+            } else {
+                panic!("Expected Grouping expression");
+            }
+        }
+
+        fn visit_literal_expr(&mut self, expr: &Expr) -> String {
+            if let Expr::Literal(value) = expr {
+                match value {
+                    Some(token) => token.lexeme.to_string(),
+                    None => "nil".to_string(),
+                }
+            } else {
+                panic!("Expected Literal expression");
+            }
+        }
+
+        fn visit_unary_expr(&mut self, expr: &Expr) -> String {
+            if let Expr::Unary(operator, right) = expr {
+                self.parenthesize(&operator.lexeme, vec![right])
+            } else {
+                panic!("Expected Unary expression");
+            }
+        }
+
+        fn print(&mut self, expr: &Expr) -> String {
+            expr.accept(self as &mut dyn Visitor<String>)
+        }
+
+        fn parenthesize(&mut self, name: &str, exprs: Vec<&Expr>) -> String {
+            let mut builder = String::new();
+            builder.push('(');
+            builder.push_str(name);
+            for expr in exprs {
+                builder.push(' ');
+                builder.push_str(&expr.accept(self as &mut dyn Visitor<String>));
+            }
+            builder.push(')');
+            builder
+        }
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        let expr = parse("1 + 2 * 3 == 4 - -5").unwrap();
+        assert_eq!(
+            Stringifier.print(&expr),
+            "(== (+ 1 (* 2 3)) (- 4 (- 5)))".to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_grouping() {
+        let expr = parse("(1 + 2) * 3").unwrap();
+        assert_eq!(Stringifier.print(&expr), "(* (group (+ 1 2)) 3)".to_string());
+    }
+
+    #[test]
+    fn test_missing_right_paren() {
+        let err = parse("(1 + 2").unwrap_err();
+        assert!(matches!(err, ParseError::MissingRightParen { .. }));
+    }
+
+    #[test]
+    fn test_expected_expression() {
+        let err = parse("1 +").unwrap_err();
+        assert!(matches!(err, ParseError::ExpectedExpression { .. }));
+    }
+
+    #[test]
+    fn test_trailing_tokens_after_expression_errors() {
+        let err = parse("1 + 2 3 4 5").unwrap_err();
+        assert!(matches!(err, ParseError::UnexpectedToken { .. }));
+    }
+
+    #[test]
+    fn test_synchronize_stops_at_semicolon() {
+        let mut scanner = Scanner::new("+ 1; 2".to_string());
+        let tokens = scanner.scan_tokens();
+        let mut parser = Parser::new(tokens);
+        parser.synchronize();
+        assert_eq!(parser.peek().token_type, TokenType::Number(2.0));
+    }
+
+    // The scanner has no identifier/keyword support yet, so this exercises
+    // the keyword-boundary branch of `synchronize` with a hand-built token
+    // stream rather than scanning `var` from source text.
+    #[test]
+    fn test_synchronize_stops_at_keyword_boundary() {
+        let tokens = vec![
+            Token::new(TokenType::Plus, "+".to_string(), 1),
+            Token::new(TokenType::Var, "var".to_string(), 1),
+            Token::new(TokenType::Eof, "".to_string(), 1),
+        ];
+        let mut parser = Parser::new(tokens);
+        parser.synchronize();
+        assert_eq!(parser.peek().token_type, TokenType::Var);
+    }
+}